@@ -1,11 +1,11 @@
 use core::{cell::UnsafeCell, marker::PhantomData, mem, ptr::NonNull};
 
-use aya_bpf_cty::c_void;
+use aya_bpf_cty::{c_long, c_void};
 
 use crate::{
     bindings::{bpf_map_def, bpf_map_type::BPF_MAP_TYPE_ARRAY},
-    helpers::bpf_map_lookup_elem,
-    maps::PinningType,
+    helpers::{bpf_map_lookup_elem, bpf_map_update_elem},
+    maps::{util::assert_suitable_alignment, PinningType},
 };
 
 /// A fixed-size array.
@@ -43,6 +43,7 @@ unsafe impl<T: Sync> Sync for Array<T> {}
 impl<T> Array<T> {
     /// Define an Array with elements of type `T` with size `max_entries`.
     pub const fn with_max_entries(max_entries: u32, flags: u32) -> Array<T> {
+        assert_suitable_alignment::<T>();
         Array {
             def: UnsafeCell::new(bpf_map_def {
                 type_: BPF_MAP_TYPE_ARRAY,
@@ -58,6 +59,7 @@ impl<T> Array<T> {
     }
 
     pub const fn pinned(max_entries: u32, flags: u32) -> Array<T> {
+        assert_suitable_alignment::<T>();
         Array {
             def: UnsafeCell::new(bpf_map_def {
                 type_: BPF_MAP_TYPE_ARRAY,
@@ -82,8 +84,52 @@ impl<T> Array<T> {
                 self.def.get() as *mut _,
                 &index as *const _ as *const c_void,
             );
-            // FIXME: alignment
             NonNull::new(value as *mut T).map(|p| p.as_ref())
         }
     }
+
+    /// Returns a raw pointer to the value stored at the given index.
+    ///
+    /// The BPF verifier requires that the option is handled correctly. You
+    /// cannot call `unwrap()` on the `Option`, for example.
+    pub fn get_ptr(&self, index: u32) -> Option<*const T> {
+        unsafe {
+            let value = bpf_map_lookup_elem(
+                self.def.get() as *mut _,
+                &index as *const _ as *const c_void,
+            );
+            NonNull::new(value as *mut T).map(|p| p.as_ptr() as *const T)
+        }
+    }
+
+    /// Returns a mutable raw pointer to the value stored at the given index.
+    ///
+    /// The BPF verifier requires that the option is handled correctly. You
+    /// cannot call `unwrap()` on the `Option`, for example.
+    pub fn get_ptr_mut(&self, index: u32) -> Option<*mut T> {
+        unsafe {
+            let value = bpf_map_lookup_elem(
+                self.def.get() as *mut _,
+                &index as *const _ as *const c_void,
+            );
+            NonNull::new(value as *mut T).map(|p| p.as_ptr())
+        }
+    }
+
+    /// Sets the value stored at the given index.
+    pub fn set(&self, index: u32, value: &T, flags: u64) -> Result<(), c_long> {
+        let ret = unsafe {
+            bpf_map_update_elem(
+                self.def.get() as *mut _,
+                &index as *const _ as *const c_void,
+                value as *const _ as *const c_void,
+                flags,
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(ret)
+        }
+    }
 }
@@ -0,0 +1,8 @@
+use core::mem;
+
+/// Kernel array-like maps only guarantee 8-byte alignment for values, so `T`
+/// must not require more than that or a `&T` or value pointer handed out by
+/// the map would be misaligned.
+pub(crate) const fn assert_suitable_alignment<T>() {
+    [(); 1][(mem::align_of::<T>() > 8) as usize];
+}